@@ -1,7 +1,10 @@
 use itertools::Itertools;
 use rand::distributions::Distribution;
 use rand::distributions::Uniform;
-use rand::thread_rng;
+use rand::distributions::WeightedIndex;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::io::Cursor;
@@ -11,6 +14,7 @@ use strum::IntoEnumIterator;
 use strum_macros::Display;
 use strum_macros::EnumIter;
 use strum_macros::EnumString;
+use tallystick::condorcet::CondorcetTally;
 use tallystick::plurality::DefaultPluralityTally;
 use tallystick::schulze::SchulzeTally;
 use tallystick::schulze::Variant;
@@ -27,8 +31,29 @@ type Ranking = Vec<(String, usize)>;
 #[strum(serialize_all = "title_case")]
 enum Method {
     Plurality,
+    Condorcet,
     SchulzeWinning,
+    SchulzeMargin,
+    SchulzeRatio,
     WeightedRandom,
+    SingleTransferableVote,
+}
+
+#[derive(Debug, Display, PartialEq, EnumString, EnumIter)]
+#[strum(serialize_all = "title_case")]
+enum Format {
+    Custom,
+    #[strum(serialize = "BLT")]
+    Blt,
+}
+
+/// Tie-break strategy for STV exclusions and elections, modeled on OpenTally's
+/// "backwards then random" scheme.
+#[derive(Debug, Display, PartialEq, EnumString, EnumIter)]
+#[strum(serialize_all = "title_case")]
+enum TieBreak {
+    Random,
+    BackwardsThenRandom,
 }
 
 fn parse_votes(raw: &String) -> anyhow::Result<WeightedRankedVote> {
@@ -55,6 +80,100 @@ fn parse_votes(raw: &String) -> anyhow::Result<WeightedRankedVote> {
     }
 }
 
+fn parse_blt(raw: &str) -> anyhow::Result<(WeightedRankedVote, Vec<String>, usize)> {
+    let mut lines = raw.lines().map(|x| x.trim()).filter(|x| !x.is_empty());
+
+    let header = lines
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse BLT, file is empty."))?;
+    let mut header = header.split_whitespace();
+    let num_candidates: usize = header
+        .next()
+        .and_then(|x| x.parse().ok())
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse BLT, missing candidate count."))?;
+    let seats: usize = header
+        .next()
+        .and_then(|x| x.parse().ok())
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse BLT, missing seat count."))?;
+
+    let mut raw_ballots: Vec<(u32, Vec<usize>)> = vec![];
+    for line in &mut lines {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens == ["0"] {
+            break;
+        }
+
+        let weight: u32 = tokens
+            .first()
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse BLT, invalid ballot weight."))?;
+
+        let mut prefs = vec![];
+        for token in &tokens[1..] {
+            if *token == "0" {
+                break;
+            }
+            let pref: usize = token
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Failed to parse BLT, invalid candidate index."))?;
+            prefs.push(pref);
+        }
+
+        raw_ballots.push((weight, prefs));
+    }
+
+    let mut candidates = vec![];
+    for _ in 0..num_candidates {
+        let line = lines
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse BLT, missing candidate name."))?;
+        candidates.push(line.trim_matches('"').to_string());
+    }
+
+    let votes = raw_ballots
+        .into_iter()
+        .map(|(weight, prefs)| {
+            let ranked: RankedVote = prefs
+                .iter()
+                .enumerate()
+                .map(|(rank, index)| {
+                    let candidate = candidates.get(index.wrapping_sub(1)).ok_or_else(|| {
+                        anyhow::anyhow!("Failed to parse BLT, candidate index out of range.")
+                    })?;
+                    Ok((candidate.clone(), rank as u32))
+                })
+                .collect::<anyhow::Result<_>>()?;
+            Ok((ranked, weight))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok((votes, candidates, seats))
+}
+
+fn to_blt(votes: &WeightedRankedVote, candidates: &[String], seats: usize) -> String {
+    let mut out = format!("{} {}\n", candidates.len(), seats);
+
+    for (ranked, weight) in votes {
+        let mut ranked = ranked.clone();
+        ranked.sort_by_key(|(_, rank)| *rank);
+        let indices = ranked
+            .iter()
+            .map(|(candidate, _)| {
+                (candidates.iter().position(|x| x == candidate).unwrap() + 1).to_string()
+            })
+            .collect_vec();
+        out.push_str(&format!("{} {} 0\n", weight, indices.join(" ")));
+    }
+    out.push_str("0\n");
+
+    for candidate in candidates {
+        out.push_str(&format!("\"{candidate}\"\n"));
+    }
+    out.push_str("\"Voter Export\"\n");
+
+    out
+}
+
 fn as_unranked_votes(votes: &WeightedRankedVote) -> anyhow::Result<WeightedUnrankedVote> {
     if votes
         .iter()
@@ -80,7 +199,9 @@ fn as_unranked_votes(votes: &WeightedRankedVote) -> anyhow::Result<WeightedUnran
 }
 
 fn consolidate_unranked_votes(votes: &WeightedUnrankedVote) -> WeightedUnrankedVote {
-    let mut map = HashMap::new();
+    // BTreeMap keeps candidates in a deterministic order, unlike HashMap, so the
+    // WeightedIndex draw order in weighted_random stays reproducible for a given seed.
+    let mut map = BTreeMap::new();
     for (canidate, weight) in votes {
         map.entry(canidate.to_string())
             .and_modify(|value| *value += *weight)
@@ -112,14 +233,14 @@ fn plurality(votes: &WeightedUnrankedVote, candidates: &Vec<String>) -> Ranking
         .collect()
 }
 
-fn schulze(votes: &WeightedRankedVote, candidates: Vec<String>) -> anyhow::Result<Ranking> {
+fn condorcet(votes: &WeightedRankedVote, candidates: Vec<String>) -> anyhow::Result<Ranking> {
     if candidates.len() == 1 {
         // avoid minor bug in tallystick where single canidate doesn't produce a winner
         return Ok(vec![(candidates[0].clone(), 0)]);
     }
 
-    let mut tally: SchulzeTally<String, u32> =
-        SchulzeTally::with_candidates(candidates.len(), Variant::Winning, candidates);
+    let mut tally: CondorcetTally<String, u32> =
+        CondorcetTally::with_candidates(candidates.len(), candidates);
 
     for (vote, weight) in votes {
         let r = tally.ranked_add_weighted(vote, *weight);
@@ -130,6 +251,8 @@ fn schulze(votes: &WeightedRankedVote, candidates: Vec<String>) -> anyhow::Resul
         }
     }
 
+    // When there is no Condorcet winner, tallystick finds the top cycle with Tarjan SCC and
+    // returns every candidate in it tied at rank 0, instead of failing.
     Ok(tally
         .winners()
         .iter()
@@ -137,26 +260,256 @@ fn schulze(votes: &WeightedRankedVote, candidates: Vec<String>) -> anyhow::Resul
         .collect())
 }
 
-fn weighted_random(votes: &WeightedUnrankedVote) -> Ranking {
-    let mut rng = thread_rng();
+fn schulze(
+    votes: &WeightedRankedVote,
+    candidates: Vec<String>,
+    variant: Variant,
+) -> anyhow::Result<Ranking> {
+    if candidates.len() == 1 {
+        // avoid minor bug in tallystick where single canidate doesn't produce a winner
+        return Ok(vec![(candidates[0].clone(), 0)]);
+    }
+
+    let invalid_vote = || {
+        anyhow::anyhow!(
+            "Invalid vote was used. Check that vote order does not list canidate twice."
+        )
+    };
 
-    let mut votes = consolidate_unranked_votes(votes);
-    let mut winners: Vec<String> = vec![];
-    while !votes.is_empty() {
-        let sum: u32 = votes.iter().map(|x| x.1).sum();
-        let mut roll = Uniform::from(1..sum + 1).sample(&mut rng);
+    // Ratio panics with an integer count type on a pairwise loss, so it needs an f64 tally.
+    let winners = if matches!(variant, Variant::Ratio) {
+        let mut tally: SchulzeTally<String, f64> =
+            SchulzeTally::with_candidates(candidates.len(), variant, candidates);
 
-        let mut found_index: usize = 0;
-        loop {
-            let weight = votes[found_index].1;
-            if roll <= weight {
+        for (vote, weight) in votes {
+            tally
+                .ranked_add_weighted(vote, *weight as f64)
+                .map_err(|_| invalid_vote())?;
+        }
+
+        tally
+            .winners()
+            .iter()
+            .map(|i| (i.candidate.to_string(), i.rank))
+            .collect()
+    } else {
+        let mut tally: SchulzeTally<String, u32> =
+            SchulzeTally::with_candidates(candidates.len(), variant, candidates);
+
+        for (vote, weight) in votes {
+            tally
+                .ranked_add_weighted(vote, *weight)
+                .map_err(|_| invalid_vote())?;
+        }
+
+        tally
+            .winners()
+            .iter()
+            .map(|i| (i.candidate.to_string(), i.rank))
+            .collect()
+    };
+
+    Ok(winners)
+}
+
+/// Treats two vote totals as equal if they differ by less than this. Totals are repeatedly
+/// scaled by a `transfer_ratio`, so exact float equality drifts apart across rounds even when
+/// two candidates are tied in principle.
+const VOTE_TOTAL_EPSILON: f64 = 1e-9;
+
+fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() < VOTE_TOTAL_EPSILON
+}
+
+/// Narrows `tied` to a single candidate. Under `BackwardsThenRandom`, ties are first broken by
+/// walking `history` (earliest round first) and keeping only the candidates that were best
+/// (`prefer_min = false`) or worst (`prefer_min = true`) at the earliest round where they
+/// differ; whatever is still tied after that falls back to `rng`. `tied` is sorted up front so
+/// the candidates reaching `rng` are in a deterministic order for a given seed.
+fn break_tie(
+    tied: &[String],
+    history: &[HashMap<String, f64>],
+    prefer_min: bool,
+    tie_break: &TieBreak,
+    rng: &mut StdRng,
+) -> String {
+    let mut remaining = tied.to_vec();
+    remaining.sort();
+
+    if *tie_break == TieBreak::BackwardsThenRandom {
+        for round in history {
+            if remaining.len() <= 1 {
                 break;
             }
-            roll -= weight;
-            found_index += 1;
+
+            let values: Vec<f64> = remaining
+                .iter()
+                .map(|c| round.get(c).copied().unwrap_or(0.0))
+                .collect();
+            let extreme = if prefer_min {
+                values.iter().copied().fold(f64::INFINITY, f64::min)
+            } else {
+                values.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+            };
+
+            remaining = remaining
+                .iter()
+                .zip(values.iter())
+                .filter(|(_, v)| approx_eq(**v, extreme))
+                .map(|(c, _)| c.clone())
+                .collect();
+        }
+    }
+
+    let index = Uniform::from(0..remaining.len()).sample(rng);
+    remaining.swap_remove(index)
+}
+
+/// Orders `candidates` from most to least votes according to `totals`, breaking ties
+/// deterministically via `break_tie` rather than relying on `HashSet`/`HashMap` iteration order.
+fn order_by_totals(
+    candidates: &HashSet<String>,
+    totals: &HashMap<String, f64>,
+    history: &[HashMap<String, f64>],
+    tie_break: &TieBreak,
+    rng: &mut StdRng,
+) -> Vec<String> {
+    let mut remaining = candidates.clone();
+    let mut ordered = vec![];
+
+    while !remaining.is_empty() {
+        let highest = remaining
+            .iter()
+            .map(|c| totals.get(c).copied().unwrap_or(0.0))
+            .fold(f64::NEG_INFINITY, f64::max);
+        let tied: Vec<String> = remaining
+            .iter()
+            .filter(|c| approx_eq(totals.get(*c).copied().unwrap_or(0.0), highest))
+            .cloned()
+            .collect();
+        let winner = break_tie(&tied, history, false, tie_break, rng);
+
+        remaining.remove(&winner);
+        ordered.push(winner);
+    }
+
+    ordered
+}
+
+fn stv(
+    votes: &WeightedRankedVote,
+    candidates: Vec<String>,
+    seats: usize,
+    tie_break: &TieBreak,
+    rng: &mut StdRng,
+) -> Ranking {
+    struct Ballot {
+        prefs: Vec<String>,
+        value: f64,
+    }
+
+    let total_valid_ballots: f64 = votes.iter().map(|(_, weight)| *weight as f64).sum();
+    let quota = (total_valid_ballots / (seats as f64 + 1.0)).floor() + 1.0;
+
+    let mut ballots: Vec<Ballot> = votes
+        .iter()
+        .map(|(ranked, weight)| {
+            let mut ranked = ranked.clone();
+            ranked.sort_by_key(|(_, rank)| *rank);
+            Ballot {
+                prefs: ranked.into_iter().map(|(c, _)| c).collect(),
+                value: *weight as f64,
+            }
+        })
+        .collect();
+
+    let mut continuing: HashSet<String> = candidates.into_iter().collect();
+    let mut elected: Vec<String> = vec![];
+    let mut history: Vec<HashMap<String, f64>> = vec![];
+
+    while elected.len() < seats && !continuing.is_empty() {
+        let mut totals: HashMap<String, f64> =
+            continuing.iter().map(|c| (c.clone(), 0.0)).collect();
+        for ballot in &ballots {
+            if let Some(c) = ballot.prefs.iter().find(|c| continuing.contains(*c)) {
+                *totals.get_mut(c).unwrap() += ballot.value;
+            }
+        }
+
+        if continuing.len() + elected.len() <= seats {
+            elected.extend(order_by_totals(
+                &continuing,
+                &totals,
+                &history,
+                tie_break,
+                rng,
+            ));
+            break;
+        }
+
+        let quota_reached: f64 = totals
+            .iter()
+            .filter(|(_, total)| **total >= quota)
+            .map(|(_, total)| *total)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let winner = if quota_reached.is_finite() {
+            let tied: Vec<String> = totals
+                .iter()
+                .filter(|(_, total)| approx_eq(**total, quota_reached))
+                .map(|(c, _)| c.clone())
+                .collect();
+            Some(break_tie(&tied, &history, false, tie_break, rng))
+        } else {
+            None
+        };
+
+        history.push(totals.clone());
+
+        if let Some(winner) = winner {
+            let winner_total = totals[&winner];
+            let transfer_ratio = (winner_total - quota) / winner_total;
+
+            for ballot in ballots.iter_mut() {
+                if ballot.prefs.iter().find(|c| continuing.contains(*c)) == Some(&winner) {
+                    ballot.value *= transfer_ratio;
+                }
+            }
+
+            continuing.remove(&winner);
+            elected.push(winner);
+        } else {
+            let lowest_total = totals.values().copied().fold(f64::INFINITY, f64::min);
+            let tied: Vec<String> = totals
+                .iter()
+                .filter(|(_, total)| approx_eq(**total, lowest_total))
+                .map(|(c, _)| c.clone())
+                .collect();
+            let loser = break_tie(&tied, &history, true, tie_break, rng);
+
+            continuing.remove(&loser);
         }
+    }
+
+    elected
+        .iter()
+        .enumerate()
+        .map(|(rank, candidate)| (candidate.to_string(), rank))
+        .collect()
+}
+
+fn weighted_random(votes: &WeightedUnrankedVote, rng: &mut StdRng) -> Ranking {
+    let mut votes = consolidate_unranked_votes(votes);
+    let mut winners: Vec<String> = vec![];
+
+    // Sampling without replacement: draw one winner proportional to the remaining weights,
+    // remove it from the pool, and repeat. The draw order becomes the elimination/rank order.
+    while !votes.is_empty() {
+        let weights = votes.iter().map(|(_, weight)| *weight);
+        let distribution = WeightedIndex::new(weights).unwrap();
+        let index = distribution.sample(rng);
 
-        winners.push(votes.swap_remove(found_index).0);
+        winners.push(votes.swap_remove(index).0);
     }
 
     winners
@@ -166,15 +519,36 @@ fn weighted_random(votes: &WeightedUnrankedVote) -> Ranking {
         .collect()
 }
 
-fn vote(votes_raw: &str, method: &str) -> anyhow::Result<Vec<(String, usize)>> {
-    let votes = parse_votes(&votes_raw.to_string())?;
-    let candidates = candidates_from_votes(&votes);
-    let method = Method::from_str(method)?;
+/// Settings gathered from the `App` form that steer how `vote()` tallies a set of ballots.
+struct VoteConfig {
+    method: String,
+    seats: usize,
+    format: String,
+    tie_break: String,
+    seed: u64,
+}
+
+fn vote(votes_raw: &str, config: &VoteConfig) -> anyhow::Result<Vec<(String, usize)>> {
+    let (votes, candidates, seats) = if config.format == "BLT" {
+        let (votes, candidates, seats) = parse_blt(votes_raw)?;
+        (votes, candidates, seats)
+    } else {
+        let votes = parse_votes(&votes_raw.to_string())?;
+        let candidates = candidates_from_votes(&votes);
+        (votes, candidates, config.seats)
+    };
+    let method = Method::from_str(&config.method)?;
+    let tie_break = TieBreak::from_str(&config.tie_break)?;
+    let mut rng = StdRng::seed_from_u64(config.seed);
 
     let winnings = match method {
-        Method::SchulzeWinning => schulze(&votes, candidates)?,
+        Method::SchulzeWinning => schulze(&votes, candidates, Variant::Winning)?,
+        Method::SchulzeMargin => schulze(&votes, candidates, Variant::Margin)?,
+        Method::SchulzeRatio => schulze(&votes, candidates, Variant::Ratio)?,
         Method::Plurality => plurality(&as_unranked_votes(&votes)?, &candidates),
-        Method::WeightedRandom => weighted_random(&as_unranked_votes(&votes)?),
+        Method::Condorcet => condorcet(&votes, candidates)?,
+        Method::WeightedRandom => weighted_random(&as_unranked_votes(&votes)?, &mut rng),
+        Method::SingleTransferableVote => stv(&votes, candidates, seats, &tie_break, &mut rng),
     };
 
     Ok(winnings)
@@ -224,7 +598,11 @@ pub fn app() -> Html {
     You can omit candidates on a ranked vote to express that the omitted candidates have the lowest rank. In other words both of these lines are functionally the same:
 
     Strawberry > Banana = Apple
-    Strawberry 
+    Strawberry
+
+    If your votes are already in the standard BLT ballot format used by tools like OpenTally, ERS, or Scotland counts, switch the "Format" dropdown to "BLT" and paste them in directly. You can also use the "Export as BLT" button to convert votes you've entered here into BLT.
+
+    Methods like Single Transferable Vote sometimes have to break a tie when excluding or electing a candidate. The "Tie Break" dropdown controls how: "Backwards Then Random" first compares each tied candidate's tally in earlier rounds, and only picks randomly if they were tied there too; "Random" always picks randomly. Random choices are drawn from the "Seed" value, so the same seed always produces the same result.
 
 
     === Explanation of Algorithms ===
@@ -240,39 +618,89 @@ pub fn app() -> Html {
     --- Weighted Random --- 
 
     Also known as a lottery.
-    
-    An unranked voting algorithm (each vote picks one candidate). The winners are picked at random picking from the pool of votes.
+
+    An unranked voting algorithm (each vote picks one candidate). The winners are picked at random picking from the pool of votes, weighted by vote count, without replacement. The draw is seeded by the "Seed" value, so the same seed and votes always reproduce the same lottery order.
     
     If ranked votes are submitted, only the first choice is used.
 
     https://en.wikipedia.org/wiki/Random_ballot
 
-    --- Schulze (Winning Variant) --- 
+    --- Condorcet ---
+
+    A ranked voting algorithm (each vote orders the candidates). The winner is whoever beats every other candidate head-to-head. Unlike Schulze, this method does not resolve cycles: if no such candidate exists, every candidate in the top cycle is shown tied at rank 1.
+
+    https://en.wikipedia.org/wiki/Condorcet_method
+
+    --- Schulze (Winning, Margin, Ratio Variants) ---
 
     A ranked voting algorithm (each vote orders the candidates). The winners are picked using a complicated process that ranks each candidate based on how well they polled overall.
 
+    The three variants differ in how the strength of a pairwise link is measured: Winning uses the raw number of ballots preferring one candidate over another, Margin uses the difference between the two pairwise counts, and Ratio uses their ratio.
+
     https://en.wikipedia.org/wiki/Schulze_method
+
+    --- Single Transferable Vote ---
+
+    A ranked, multi-winner voting algorithm that fills a configurable number of "Seats". Candidates reaching the Droop quota are elected and their surplus votes transfer to the next preference; if nobody meets quota, the weakest candidate is excluded and their votes transfer instead.
+
+    https://en.wikipedia.org/wiki/Single_transferable_vote
     "#;
 
     let instructions = instructions.trim().lines().map(|x| x.trim()).join("\n");
 
     let raw_votes = use_state(|| instructions.to_string());
     let alg = use_state(|| Method::Plurality.to_string());
+    let seats = use_state(|| "1".to_string());
+    let format = use_state(|| Format::Custom.to_string());
+    let tie_break = use_state(|| TieBreak::BackwardsThenRandom.to_string());
+    let seed = use_state(|| "1".to_string());
     let winners: UseStateHandle<Vec<(String, usize)>> = use_state(Vec::new);
     let error = use_state(|| "".to_string());
 
     let onclick = {
         let raw_votes = raw_votes.clone();
         let method = alg.clone();
+        let seats = seats.clone();
+        let format = format.clone();
+        let tie_break = tie_break.clone();
+        let seed = seed.clone();
         let winners = winners.clone();
         let error = error.clone();
-        Callback::from(move |_| match vote(&raw_votes, &method) {
-            Ok(w) => {
-                winners.set(w);
+        Callback::from(move |_| {
+            let config = VoteConfig {
+                method: (*method).clone(),
+                seats: seats.parse().unwrap_or(1),
+                format: (*format).clone(),
+                tie_break: (*tie_break).clone(),
+                seed: seed.parse().unwrap_or(0),
+            };
+            match vote(&raw_votes, &config) {
+                Ok(w) => {
+                    winners.set(w);
+                    error.set("".to_string());
+                }
+                Err(e) => {
+                    winners.set(vec![]);
+                    error.set(e.to_string());
+                }
+            }
+        })
+    };
+
+    let onexport = {
+        let raw_votes = raw_votes.clone();
+        let seats = seats.clone();
+        let format = format.clone();
+        let error = error.clone();
+        Callback::from(move |_| match parse_votes(&raw_votes) {
+            Ok(votes) => {
+                let candidates = candidates_from_votes(&votes);
+                let seats: usize = seats.parse().unwrap_or(1);
+                raw_votes.set(to_blt(&votes, &candidates, seats));
+                format.set(Format::Blt.to_string());
                 error.set("".to_string());
             }
             Err(e) => {
-                winners.set(vec![]);
                 error.set(e.to_string());
             }
         })
@@ -308,6 +736,66 @@ pub fn app() -> Html {
         })
     };
 
+    let on_format_change = {
+        let format = format.clone();
+        let winners = winners.clone();
+        let error = error.clone();
+        Callback::from(move |e: Event| {
+            winners.set(vec![]);
+            error.set("".to_string());
+            if let Some(target) = e.target().and_then(|event_target: web_sys::EventTarget| {
+                event_target.dyn_into::<web_sys::HtmlSelectElement>().ok()
+            }) {
+                format.set(target.value());
+            }
+        })
+    };
+
+    let on_seats_input = {
+        let seats = seats.clone();
+        let winners = winners.clone();
+        let error = error.clone();
+        Callback::from(move |e: InputEvent| {
+            winners.set(vec![]);
+            error.set("".to_string());
+            if let Some(data) = e.target().and_then(|event_target: web_sys::EventTarget| {
+                event_target.dyn_into::<web_sys::HtmlInputElement>().ok()
+            }) {
+                seats.set(data.value());
+            }
+        })
+    };
+
+    let on_tie_break_change = {
+        let tie_break = tie_break.clone();
+        let winners = winners.clone();
+        let error = error.clone();
+        Callback::from(move |e: Event| {
+            winners.set(vec![]);
+            error.set("".to_string());
+            if let Some(target) = e.target().and_then(|event_target: web_sys::EventTarget| {
+                event_target.dyn_into::<web_sys::HtmlSelectElement>().ok()
+            }) {
+                tie_break.set(target.value());
+            }
+        })
+    };
+
+    let on_seed_input = {
+        let seed = seed.clone();
+        let winners = winners.clone();
+        let error = error.clone();
+        Callback::from(move |e: InputEvent| {
+            winners.set(vec![]);
+            error.set("".to_string());
+            if let Some(data) = e.target().and_then(|event_target: web_sys::EventTarget| {
+                event_target.dyn_into::<web_sys::HtmlInputElement>().ok()
+            }) {
+                seed.set(data.value());
+            }
+        })
+    };
+
     html! {
         <div>
             <h1> { "Voter" } </h1>
@@ -316,6 +804,14 @@ pub fn app() -> Html {
                 <textarea {oninput} value={(*raw_votes).to_string()}></textarea>
             </div>
             <div>
+                <p>{"Format: "}
+                    <select onchange={on_format_change}>
+                        { for Format::iter()
+                                .map(|x| html!{ <option selected={ (*format) == x.to_string() }> {x.to_string()} </option> })
+                        }
+                    </select>
+                    <button onclick={onexport}>{ "Export as BLT" }</button>
+                </p>
                 <p>{"Method: "}
                     <select {onchange}>
                         { for Method::iter()
@@ -323,6 +819,19 @@ pub fn app() -> Html {
                         }
                     </select>
                 </p>
+                <p>{"Seats: "}
+                    <input type="number" min="1" oninput={on_seats_input} value={(*seats).to_string()} />
+                </p>
+                <p>{"Tie Break: "}
+                    <select onchange={on_tie_break_change}>
+                        { for TieBreak::iter()
+                                .map(|x| html!{ <option selected={ (*tie_break) == x.to_string() }> {x.to_string()} </option> })
+                        }
+                    </select>
+                </p>
+                <p>{"Seed: "}
+                    <input type="number" oninput={on_seed_input} value={(*seed).to_string()} />
+                </p>
                 <button {onclick}>{ "Calculate" }</button>
             </div>
             if !(*error).is_empty() {